@@ -2,12 +2,18 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use anyhow::{Context, Result, bail};
 use chrono::{DateTime, Utc};
-use clap::Parser;
+use clap::{Parser, ValueEnum};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
 use ulid::{Generator, Ulid};
+use uuid::Uuid;
 
 // =========================================================
 // CLI Definition
@@ -17,8 +23,10 @@ use ulid::{Generator, Ulid};
 #[derive(Parser)]
 #[command(version, about)]
 struct Cli {
-    /// Parse and display the components of an existing ULID.
-    #[arg(long, conflicts_with_all = ["timestamp", "datetime", "count", "lowercase"])]
+    /// Parse and display the components of an existing ULID. Pass `-` to
+    /// read one ULID per line from stdin and print a compact table instead
+    /// of the detailed block.
+    #[arg(long, conflicts_with_all = ["timestamp", "datetime", "count", "lowercase", "uuid", "format"])]
     inspect: Option<String>,
 
     /// Pin the timestamp to a specific Unix epoch value in milliseconds.
@@ -36,6 +44,77 @@ struct Cli {
     /// Output in lowercase.
     #[arg(short, long)]
     lowercase: bool,
+
+    /// Print each generated ULID as its canonical hyphenated UUID string.
+    ///
+    /// A ULID is 128-bit compatible with UUID (same layout, different text
+    /// encoding), so this is a lossless reinterpretation useful when
+    /// bridging to UUID-based systems.
+    #[arg(long, conflicts_with = "format")]
+    uuid: bool,
+
+    /// Output encoding for generated ULIDs.
+    #[arg(long, value_enum)]
+    format: Option<OutputFormat>,
+
+    /// When `--inspect` fails to parse strictly, retry after normalizing
+    /// common typos (uppercasing, stripping hyphens/whitespace, and
+    /// substituting the Crockford aliases `I`/`L` -> `1` and `O` -> `0`).
+    #[arg(long)]
+    lenient: bool,
+
+    /// Seed the random component for reproducible output — useful for test
+    /// fixtures and golden-file snapshots. The output is fully deterministic
+    /// only together with `--timestamp` or `--datetime`; otherwise the
+    /// timestamp is still taken from the current time.
+    #[arg(long)]
+    seed: Option<u64>,
+
+    /// Columns to print in batch `--inspect` mode.
+    #[arg(
+        long,
+        value_enum,
+        value_delimiter = ',',
+        default_values_t = [Column::Ulid, Column::UnixMs, Column::Rfc3339],
+    )]
+    columns: Vec<Column>,
+
+    /// Persist the last-emitted ULID to this file and use it to guarantee
+    /// strict ordering across separate invocations, not just within one
+    /// process (which `Generator` already covers on its own). Has no effect
+    /// when `--seed` is given: the state file is then neither read nor
+    /// written.
+    #[arg(long)]
+    monotonic_state: Option<PathBuf>,
+}
+
+/// A selectable field in batch `--inspect` output.
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Column {
+    /// The canonical Crockford ULID string.
+    Ulid,
+    /// The embedded timestamp as raw Unix milliseconds.
+    UnixMs,
+    /// The embedded timestamp as RFC 3339.
+    Rfc3339,
+}
+
+/// The available output encodings for `--format`.
+///
+/// All variants are derived from the same underlying 128-bit value as the
+/// default Crockford string, just rendered differently.
+#[derive(Clone, Copy, ValueEnum)]
+enum OutputFormat {
+    /// The default 26-char Crockford Base32 string.
+    Canonical,
+    /// 32 lowercase hex digits of the 128-bit value.
+    Hex,
+    /// The 128-bit value as a decimal integer.
+    U128,
+    /// The 16 big-endian bytes, written straight to stdout for piping.
+    Raw,
+    /// The canonical hyphenated UUID string.
+    Uuid,
 }
 
 // =========================================================
@@ -45,10 +124,10 @@ struct Cli {
 fn main() -> Result<()> {
     let cli = Cli::parse();
 
-    if let Some(ref input) = cli.inspect {
-        inspect_ulid(input)?;
-    } else {
-        generate_ulids(&cli)?;
+    match cli.inspect.as_deref() {
+        Some("-") => inspect_batch(&cli)?,
+        Some(input) => inspect_ulid(input, cli.lenient)?,
+        None => generate_ulids(&cli)?,
     }
 
     Ok(())
@@ -58,13 +137,56 @@ fn main() -> Result<()> {
 // Inspect Mode
 // =========================================================
 
+/// Parses a single `--inspect` argument into the `Ulid` it represents, plus
+/// its UUID reinterpretation if the input was itself a hyphenated UUID.
+///
+/// A 36-char hyphenated UUID is accepted alongside the canonical 26-char
+/// Crockford string — ULIDs and UUIDs share the same 128-bit layout, so the
+/// input is reinterpreted as a ULID either way.
+///
+/// When `lenient` is set and a strict Crockford parse fails, the input is
+/// normalized (see [`normalize_crockford`]) and retried, with a warning
+/// listing the characters that were corrected.
+fn parse_inspect_input(input: &str, lenient: bool) -> Result<(Ulid, Option<Uuid>)> {
+    let trimmed = input.trim();
+
+    if trimmed.len() == 36 {
+        let uuid: Uuid = trimmed
+            .parse()
+            .with_context(|| format!("parse `{trimmed}` as UUID"))?;
+        return Ok((Ulid::from(uuid.as_u128()), Some(uuid)));
+    }
+
+    match trimmed.parse::<Ulid>() {
+        Ok(ulid) => Ok((ulid, None)),
+        Err(_) if lenient => {
+            let (normalized, corrections) = normalize_crockford(trimmed);
+
+            if !corrections.is_empty() {
+                eprintln!(
+                    "warning: corrected {} character(s) for lenient parse:",
+                    corrections.len()
+                );
+                for correction in &corrections {
+                    eprintln!("  {correction}");
+                }
+            }
+
+            let ulid: Ulid = normalized
+                .parse()
+                .with_context(|| format!("parse normalized `{normalized}` as ULID"))?;
+
+            Ok((ulid, None))
+        }
+        Err(err) => Err(err).with_context(|| format!("parse `{trimmed}` as ULID")),
+    }
+}
+
 /// Parses an existing ULID string and prints its components: the canonical
 /// representation, the embedded timestamp (both as ISO 8601 and raw Unix
 /// milliseconds), and the 80-bit random payload as a hex string.
-fn inspect_ulid(input: &str) -> Result<()> {
-    let ulid: Ulid = input
-        .parse()
-        .with_context(|| format!("parse `{input}` as ULID"))?;
+fn inspect_ulid(input: &str, lenient: bool) -> Result<()> {
+    let (ulid, uuid) = parse_inspect_input(input, lenient)?;
 
     let timestamp_ms = ulid.timestamp_ms();
 
@@ -77,6 +199,7 @@ fn inspect_ulid(input: &str) -> Result<()> {
     let random = ulid.random();
 
     println!("ULID:      {ulid}");
+    println!("UUID:      {}", uuid.unwrap_or_else(|| Uuid::from_u128(ulid.0)));
     println!("Timestamp: {}", datetime.to_rfc3339());
     println!("Unix ms:   {timestamp_ms}");
     println!("Random:    0x{random:020x}");
@@ -84,6 +207,97 @@ fn inspect_ulid(input: &str) -> Result<()> {
     Ok(())
 }
 
+/// Reads one ULID (or hyphenated UUID) per line from stdin and prints a
+/// compact one-line-per-entry table of the columns selected by
+/// `--columns`, instead of the detailed block `inspect_ulid` prints for a
+/// single argument. Lines that fail to parse are reported to stderr and
+/// skipped, so one bad row doesn't abort the whole batch.
+fn inspect_batch(cli: &Cli) -> Result<()> {
+    println!("{}", header_row(&cli.columns));
+
+    for line in io::stdin().lock().lines() {
+        let line = line.context("read line from stdin")?;
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        match parse_inspect_input(trimmed, cli.lenient) {
+            Ok((ulid, _)) => println!("{}", row(&cli.columns, ulid)),
+            Err(err) => eprintln!("warning: skipping `{trimmed}`: {err:#}"),
+        }
+    }
+
+    Ok(())
+}
+
+/// Renders the header line for `inspect_batch`'s table, matching the column
+/// order and naming used by [`row`].
+fn header_row(columns: &[Column]) -> String {
+    columns
+        .iter()
+        .map(|column| match column {
+            Column::Ulid => "ULID",
+            Column::UnixMs => "Unix ms",
+            Column::Rfc3339 => "RFC 3339",
+        })
+        .collect::<Vec<_>>()
+        .join("\t")
+}
+
+/// Renders one tab-separated table row for `inspect_batch`, containing only
+/// the selected `columns` in order.
+fn row(columns: &[Column], ulid: Ulid) -> String {
+    let datetime: DateTime<Utc> = ulid.datetime().into();
+
+    columns
+        .iter()
+        .map(|column| match column {
+            Column::Ulid => ulid.to_string(),
+            Column::UnixMs => ulid.timestamp_ms().to_string(),
+            Column::Rfc3339 => datetime.to_rfc3339(),
+        })
+        .collect::<Vec<_>>()
+        .join("\t")
+}
+
+/// Normalizes a possibly-mistyped Crockford ULID string: uppercases it,
+/// strips hyphens and whitespace, and applies the standard Crockford alias
+/// substitutions (`I`/`L` -> `1`, `O` -> `0`).
+///
+/// Returns the normalized string alongside a human-readable description of
+/// each correction that was applied, so callers can report what changed.
+fn normalize_crockford(input: &str) -> (String, Vec<String>) {
+    let mut corrections = Vec::new();
+
+    let normalized = input
+        .chars()
+        .enumerate()
+        .filter(|(_, c)| !c.is_whitespace() && *c != '-')
+        .map(|(i, c)| {
+            let upper = c.to_ascii_uppercase();
+            let corrected = match upper {
+                'I' | 'L' => '1',
+                'O' => '0',
+                other => other,
+            };
+
+            // Only a genuine alias substitution counts as a correction; a
+            // plain lowercase-to-uppercase change is expected normalization,
+            // not a typo. Report positions against the original `input`, not
+            // the whitespace/hyphen-stripped stream, so they line up with
+            // what the user typed.
+            if matches!(upper, 'I' | 'L' | 'O') {
+                corrections.push(format!("position {i}: '{c}' -> '{corrected}'"));
+            }
+
+            corrected
+        })
+        .collect();
+
+    (normalized, corrections)
+}
+
 // =========================================================
 // Generate Mode
 // =========================================================
@@ -92,8 +306,21 @@ fn inspect_ulid(input: &str) -> Result<()> {
 /// When multiple ULIDs are requested, a monotonic generator ensures each
 /// successive value is strictly greater than the previous one — even when
 /// they share the same millisecond timestamp.
+///
+/// `--seed` replaces the default (thread-random, process-local) `Generator`
+/// with a seeded random source, trading unpredictability for
+/// reproducibility.
 fn generate_ulids(cli: &Cli) -> Result<()> {
     let pinned_time = resolve_timestamp(cli)?;
+
+    if let Some(seed) = cli.seed {
+        return generate_ulids_seeded(cli, seed, pinned_time);
+    }
+
+    if let Some(ref path) = cli.monotonic_state {
+        return generate_ulids_with_monotonic_state(cli, path, pinned_time);
+    }
+
     let mut generator = Generator::new();
 
     for _ in 0..cli.count {
@@ -106,15 +333,154 @@ fn generate_ulids(cli: &Cli) -> Result<()> {
                 .context("generate ULID (random bits overflow)"),
         }?;
 
-        let formatted = if cli.lowercase {
-            ulid.to_string().to_lowercase()
-        } else {
-            ulid.to_string()
+        emit_ulid(cli, ulid)?;
+    }
+
+    Ok(())
+}
+
+/// Generates `cli.count` ULIDs, checking the first one against the last
+/// ULID persisted by a prior invocation of `--monotonic-state <path>` (if
+/// any) and bumping it forward when needed. Unlike the default path, the
+/// rest of the batch is *not* drawn from an independent `Generator` — it is
+/// derived by incrementing the (possibly bumped) first ULID, exactly as
+/// `generate_ulids_seeded` does, since a fresh `Generator`'s own sequence
+/// could otherwise fall below the bumped value and produce a non-monotonic
+/// batch. The final emitted ULID is written back to `path` on success,
+/// ready for the next invocation.
+fn generate_ulids_with_monotonic_state(
+    cli: &Cli,
+    path: &Path,
+    pinned_time: Option<SystemTime>,
+) -> Result<()> {
+    let previous = load_monotonic_state(path)?;
+    let resolved_time = pinned_time.unwrap_or_else(SystemTime::now);
+    let mut last_emitted: Option<Ulid> = None;
+
+    for _ in 0..cli.count {
+        let ulid = match last_emitted {
+            Some(prev) => prev
+                .increment()
+                .context("generate ULID (random bits overflow)")?,
+            None => {
+                let first = Ulid::from_datetime(resolved_time);
+                match previous {
+                    Some(previous) => ensure_monotonic(first, previous),
+                    None => first,
+                }
+            }
+        };
+
+        last_emitted = Some(ulid);
+        emit_ulid(cli, ulid)?;
+    }
+
+    if let Some(ulid) = last_emitted {
+        save_monotonic_state(path, ulid)?;
+    }
+
+    Ok(())
+}
+
+/// Bumps `ulid` forward if it is not strictly greater than `previous`: first
+/// by incrementing `previous`'s random component, or, if that overflows, by
+/// moving to the next millisecond instead — mirroring what `Generator`
+/// does internally when two calls land in the same millisecond.
+fn ensure_monotonic(ulid: Ulid, previous: Ulid) -> Ulid {
+    if ulid > previous {
+        return ulid;
+    }
+
+    previous.increment().unwrap_or_else(|| {
+        Ulid::from_datetime(UNIX_EPOCH + Duration::from_millis(previous.timestamp_ms() + 1))
+    })
+}
+
+/// Reads the last-emitted ULID written by a prior `--monotonic-state`
+/// invocation, if the state file exists yet.
+fn load_monotonic_state(path: &Path) -> Result<Option<Ulid>> {
+    match fs::read_to_string(path) {
+        Ok(contents) => {
+            let ulid = contents
+                .trim()
+                .parse()
+                .with_context(|| format!("parse monotonic state file `{}` as ULID", path.display()))?;
+            Ok(Some(ulid))
+        }
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => {
+            Err(err).with_context(|| format!("read monotonic state file `{}`", path.display()))
+        }
+    }
+}
+
+/// Persists `ulid` to the monotonic state file so the next invocation can
+/// pick up where this one left off.
+fn save_monotonic_state(path: &Path, ulid: Ulid) -> Result<()> {
+    fs::write(path, ulid.to_string())
+        .with_context(|| format!("write monotonic state file `{}`", path.display()))
+}
+
+/// Generates `cli.count` ULIDs from a `StdRng` seeded with `seed`, pinned to
+/// `pinned_time` (or the current time if unset). The first ULID draws its
+/// random component straight from the seeded source; each subsequent one is
+/// derived by incrementing the previous, exactly as `Generator` does
+/// internally for same-millisecond ULIDs. When `pinned_time` is set, both
+/// the seed and the timestamp are fixed inputs, so the whole sequence is
+/// deterministic; otherwise only the random component is.
+fn generate_ulids_seeded(cli: &Cli, seed: u64, pinned_time: Option<SystemTime>) -> Result<()> {
+    let resolved_time = pinned_time.unwrap_or_else(SystemTime::now);
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut previous: Option<Ulid> = None;
+
+    for _ in 0..cli.count {
+        let ulid = match previous {
+            Some(prev) => prev
+                .increment()
+                .context("generate ULID (random bits overflow)")?,
+            None => Ulid::from_datetime_with_source(resolved_time, &mut rng),
         };
 
-        println!("{formatted}");
+        previous = Some(ulid);
+        emit_ulid(cli, ulid)?;
+    }
+
+    Ok(())
+}
+
+/// Renders a single generated ULID according to `--format`/`--uuid`/
+/// `--lowercase` and writes it to stdout.
+fn emit_ulid(cli: &Cli, ulid: Ulid) -> Result<()> {
+    let format = cli.format.unwrap_or(if cli.uuid {
+        OutputFormat::Uuid
+    } else {
+        OutputFormat::Canonical
+    });
+
+    // `raw` writes bytes straight to stdout rather than a formatted,
+    // newline-terminated string, so it is handled separately from the
+    // other variants below.
+    if matches!(format, OutputFormat::Raw) {
+        std::io::stdout()
+            .write_all(&ulid.to_bytes())
+            .context("write raw ULID bytes to stdout")?;
+        return Ok(());
     }
 
+    let value: u128 = ulid.0;
+    // The hyphenated UUID representation is canonically lowercase, so
+    // `--lowercase` only affects the Crockford ULID output.
+    let formatted = match format {
+        OutputFormat::Canonical if cli.lowercase => ulid.to_string().to_lowercase(),
+        OutputFormat::Canonical => ulid.to_string(),
+        OutputFormat::Hex => format!("{value:032x}"),
+        OutputFormat::U128 => value.to_string(),
+        OutputFormat::Uuid => Uuid::from_u128(value).to_string(),
+        OutputFormat::Raw => unreachable!("handled above"),
+    };
+
+    println!("{formatted}");
+
     Ok(())
 }
 